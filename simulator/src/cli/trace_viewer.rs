@@ -1,18 +1,46 @@
 use crate::theme::ansi::apply;
 use crate::theme::load_theme;
 
-pub fn render_trace() {
-    let theme = load_theme();
+/// Rendering backend for [`render_trace`], modeled on rustdoc's own
+/// `OutputFormat` split: `Terminal`, `Html`, and `Json` targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// ANSI escape codes, for printing straight to a terminal.
+    Terminal,
+    /// `<span class="...">` elements, for embedding in a web trace viewer.
+    Html,
+    /// One JSON object per line, for downstream tools to consume.
+    Json,
+}
 
-    println!(
-        "{} {}",
-        apply(&theme.span, "SPAN"),
-        apply(&theme.event, "User logged in")
-    );
+pub fn render_trace(fmt: OutputFormat) -> String {
+    let theme = load_theme();
 
-    println!(
-        "{} {}",
-        apply(&theme.error, "ERROR"),
-        apply(&theme.error, "Connection failed")
-    );
+    match fmt {
+        OutputFormat::Terminal => format!(
+            "{} {}\n{} {}\n",
+            apply(&theme.span, "SPAN"),
+            apply(&theme.event, "User logged in"),
+            apply(&theme.error, "ERROR"),
+            apply(&theme.error, "Connection failed"),
+        ),
+        OutputFormat::Html => format!(
+            "<span class=\"span\" style=\"color:{span}\">SPAN</span> \
+             <span class=\"event\" style=\"color:{event}\">User logged in</span>\n\
+             <span class=\"error\" style=\"color:{error}\">ERROR</span> \
+             <span class=\"error\" style=\"color:{error}\">Connection failed</span>\n",
+            span = theme.span,
+            event = theme.event,
+            error = theme.error,
+        ),
+        OutputFormat::Json => format!(
+            "{{\"kind\":\"span\",\"text\":\"SPAN\",\"color\":\"{span}\"}}\n\
+             {{\"kind\":\"event\",\"text\":\"User logged in\",\"color\":\"{event}\"}}\n\
+             {{\"kind\":\"error\",\"text\":\"ERROR\",\"color\":\"{error}\"}}\n\
+             {{\"kind\":\"error\",\"text\":\"Connection failed\",\"color\":\"{error}\"}}\n",
+            span = theme.span,
+            event = theme.event,
+            error = theme.error,
+        ),
+    }
 }